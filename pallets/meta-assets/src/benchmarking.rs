@@ -0,0 +1,177 @@
+//! Benchmarking setup for `pallet_meta_assets`.
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as MetaAssets;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	sp_runtime::traits::Hash,
+	traits::{Currency, Hooks},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_std::vec;
+
+const SEED: u32 = 0;
+
+/// Grant `who` enough balance to cover any deposit taken during a benchmark.
+fn fund<T: Config>(who: &T::AccountId) {
+	let balance = T::Currency::minimum_balance() + T::AssetDeposit::get() * 1_000u32.into();
+	let _ = T::Currency::make_free_balance_be(who, balance);
+}
+
+benchmarks! {
+	add_asset {
+		let n in T::NameMinLength::get() .. T::StringLimit::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let name = vec![b'x'; n as usize];
+		let meta = vec![b'm'; n as usize];
+	}: _(RawOrigin::Signed(caller), name, Some(meta))
+
+	add_asset_with_expiry {
+		let n in T::NameMinLength::get() .. T::StringLimit::get();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let name = vec![b'x'; n as usize];
+		let meta = vec![b'm'; n as usize];
+		let expiry: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number() + 1u32.into();
+	}: _(RawOrigin::Signed(caller), name, Some(meta), Some(expiry))
+
+	transfer_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let name = vec![b'x'; 8];
+		MetaAssets::<T>::add_asset(RawOrigin::Signed(caller.clone()).into(), name.clone(), None)?;
+		let asset = AssetItem::<T> {
+			name: name.try_into().unwrap(),
+			owner: caller.clone(),
+			deposit: T::AssetDeposit::get(),
+		};
+		let hash = T::Hashing::hash_of(&asset);
+	}: _(RawOrigin::Signed(caller), hash, dest)
+
+	update_meta {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let name = vec![b'x'; 8];
+		MetaAssets::<T>::add_asset(RawOrigin::Signed(caller.clone()).into(), name.clone(), None)?;
+		let asset = AssetItem::<T> {
+			name: name.try_into().unwrap(),
+			owner: caller.clone(),
+			deposit: T::AssetDeposit::get(),
+		};
+		let hash = T::Hashing::hash_of(&asset);
+	}: _(RawOrigin::Signed(caller), hash, Some(vec![b'm'; 16]))
+
+	register_admin {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let name = vec![b'x'; 8];
+		MetaAssets::<T>::add_asset(RawOrigin::Signed(caller.clone()).into(), name.clone(), None)?;
+		let asset = AssetItem::<T> {
+			name: name.try_into().unwrap(),
+			owner: caller.clone(),
+			deposit: T::AssetDeposit::get(),
+		};
+		let hash = T::Hashing::hash_of(&asset);
+	}: _(RawOrigin::Signed(caller), hash, admin)
+
+	revoke_admin {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let name = vec![b'x'; 8];
+		MetaAssets::<T>::add_asset(RawOrigin::Signed(caller.clone()).into(), name.clone(), None)?;
+		let asset = AssetItem::<T> {
+			name: name.try_into().unwrap(),
+			owner: caller.clone(),
+			deposit: T::AssetDeposit::get(),
+		};
+		let hash = T::Hashing::hash_of(&asset);
+		MetaAssets::<T>::register_admin(RawOrigin::Signed(caller.clone()).into(), hash, admin.clone())?;
+	}: _(RawOrigin::Signed(caller), hash, admin)
+
+	remove_asset {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let name = vec![b'x'; 8];
+		MetaAssets::<T>::add_asset(RawOrigin::Signed(caller.clone()).into(), name.clone(), None)?;
+		let asset = AssetItem::<T> {
+			name: name.try_into().unwrap(),
+			owner: caller.clone(),
+			deposit: T::AssetDeposit::get(),
+		};
+		let hash = T::Hashing::hash_of(&asset);
+	}: _(RawOrigin::Signed(caller), hash)
+
+	list_for_sale {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let name = vec![b'x'; 8];
+		MetaAssets::<T>::add_asset(RawOrigin::Signed(caller.clone()).into(), name.clone(), None)?;
+		let asset = AssetItem::<T> {
+			name: name.try_into().unwrap(),
+			owner: caller.clone(),
+			deposit: T::AssetDeposit::get(),
+		};
+		let hash = T::Hashing::hash_of(&asset);
+		let price = T::AssetDeposit::get();
+	}: _(RawOrigin::Signed(caller), hash, price)
+
+	unlist {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let name = vec![b'x'; 8];
+		MetaAssets::<T>::add_asset(RawOrigin::Signed(caller.clone()).into(), name.clone(), None)?;
+		let asset = AssetItem::<T> {
+			name: name.try_into().unwrap(),
+			owner: caller.clone(),
+			deposit: T::AssetDeposit::get(),
+		};
+		let hash = T::Hashing::hash_of(&asset);
+		let price = T::AssetDeposit::get();
+		MetaAssets::<T>::list_for_sale(RawOrigin::Signed(caller.clone()).into(), hash, price)?;
+	}: _(RawOrigin::Signed(caller), hash)
+
+	buy_asset {
+		let seller: T::AccountId = whitelisted_caller();
+		fund::<T>(&seller);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		fund::<T>(&buyer);
+		let name = vec![b'x'; 8];
+		MetaAssets::<T>::add_asset(RawOrigin::Signed(seller.clone()).into(), name.clone(), None)?;
+		let asset = AssetItem::<T> {
+			name: name.try_into().unwrap(),
+			owner: seller.clone(),
+			deposit: T::AssetDeposit::get(),
+		};
+		let hash = T::Hashing::hash_of(&asset);
+		let price = T::AssetDeposit::get();
+		MetaAssets::<T>::list_for_sale(RawOrigin::Signed(seller.clone()).into(), hash, price)?;
+	}: _(RawOrigin::Signed(buyer), hash)
+
+	on_initialize {
+		let n in 0 .. T::MaxExpiringPerBlock::get();
+		let expiry: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		for i in 0 .. n {
+			// Distinct names so each asset hashes uniquely and the index holds `n` entries.
+			let mut name = vec![b'x'; 8];
+			name.extend_from_slice(&i.to_le_bytes());
+			MetaAssets::<T>::add_asset_with_expiry(
+				RawOrigin::Signed(caller.clone()).into(),
+				name,
+				None,
+				Some(expiry),
+			)?;
+		}
+	}: {
+		MetaAssets::<T>::on_initialize(expiry);
+	}
+
+	impl_benchmark_test_suite!(MetaAssets, crate::mock::new_test_ext(), crate::mock::Test);
+}
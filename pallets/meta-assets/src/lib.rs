@@ -2,29 +2,86 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
 
-	use frame_support::{inherent::Vec, pallet_prelude::*, sp_runtime::traits::Hash};
+	use crate::weights::WeightInfo;
+	use frame_support::{
+		inherent::Vec,
+		pallet_prelude::*,
+		sp_runtime::traits::{Hash, Saturating, Zero},
+		traits::{Currency, ExistenceRequirement, ReservableCurrency},
+	};
 	use frame_system::pallet_prelude::*;
 
+	type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// A length-bounded byte string used for asset names and metadata.
+	pub type BoundedName<T> = BoundedVec<u8, <T as Config>::StringLimit>;
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The currency used to reserve deposits when assets and metadata are stored.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit reserved from the signer for each registered asset.
+		#[pallet::constant]
+		type AssetDeposit: Get<BalanceOf<Self>>;
+
+		/// The base deposit reserved when metadata is attached to an asset.
+		#[pallet::constant]
+		type MetadataDepositBase: Get<BalanceOf<Self>>;
+
+		/// The per-byte deposit reserved for the stored metadata.
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The maximum length, in bytes, of an asset name or metadata value.
+		#[pallet::constant]
+		type StringLimit: Get<u32>;
+
+		/// The minimum length, in bytes, of an asset name.
+		#[pallet::constant]
+		type NameMinLength: Get<u32>;
+
+		/// The maximum number of assets that may be scheduled to expire in a single block.
+		#[pallet::constant]
+		type MaxExpiringPerBlock: Get<u32>;
+
+		/// The maximum number of admins that may be registered for a single asset.
+		#[pallet::constant]
+		type MaxAdminsPerAsset: Get<u32>;
+
+		/// Weight information for the dispatchables in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
-	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
 	pub struct AssetItem<T: Config> {
-		pub name: Vec<u8>,
+		pub name: BoundedName<T>,
 		pub owner: <T as frame_system::Config>::AccountId,
+		/// Total amount reserved from the owner for this asset (asset + metadata deposit).
+		pub deposit: BalanceOf<T>,
 	}
 
 	#[pallet::storage]
@@ -34,12 +91,54 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn assets_meta)]
 	pub type MetadataStore<T: Config> =
-		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, T::AccountId, Option<Vec<u8>>>;
+		StorageMap<_, Twox64Concat, T::Hash, Option<BoundedName<T>>>;
+
+	/// Accounts authorised to edit an asset's metadata, keyed by asset hash and account.
+	#[pallet::storage]
+	#[pallet::getter(fn is_admin)]
+	pub type AdminsStore<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::Hash, Twox64Concat, T::AccountId, ()>;
+
+	/// The number of admins currently registered for each asset.
+	#[pallet::storage]
+	pub type AdminCount<T: Config> = StorageMap<_, Twox64Concat, T::Hash, u32, ValueQuery>;
+
+	/// Assets currently listed for sale, keyed by asset hash, with the listed price.
+	#[pallet::storage]
+	#[pallet::getter(fn listing)]
+	pub type ListingStore<T: Config> = StorageMap<_, Twox64Concat, T::Hash, BalanceOf<T>>;
+
+	/// Assets scheduled to expire, indexed by the block at which they are reclaimed.
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_at)]
+	pub type ExpiringAssets<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::Hash, T::MaxExpiringPerBlock>,
+		ValueQuery,
+	>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		AssetWasStored(Vec<u8>, T::AccountId),
+		/// A deposit was reserved from an account. [who, amount]
+		DepositReserved(T::AccountId, BalanceOf<T>),
+		/// A deposit was returned to an account. [who, amount]
+		DepositReturned(T::AccountId, BalanceOf<T>),
+		/// An asset reached its expiry block and was reclaimed. [hash]
+		AssetExpired(T::Hash),
+		/// An admin was registered for an asset. [hash, admin]
+		AdminRegistered(T::Hash, T::AccountId),
+		/// An admin was revoked from an asset. [hash, admin]
+		AdminRevoked(T::Hash, T::AccountId),
+		/// An asset was sold through the marketplace. [hash, from, to, price]
+		AssetSold(T::Hash, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// An asset was listed for sale. [hash, price]
+		AssetListed(T::Hash, BalanceOf<T>),
+		/// An asset's listing was removed. [hash]
+		AssetUnlisted(T::Hash),
 	}
 
 	#[pallet::error]
@@ -50,6 +149,38 @@ pub mod pallet {
 		LongNameProvided,
 		InvalidOwner,
 		InvalidHash,
+		TooManyExpiringAssets,
+		TooManyAdmins,
+		NotAnAdmin,
+		NotListed,
+		AssetAlreadyExists,
+		ExpiryInPast,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Reclaim every asset scheduled to expire at block `n`.
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let expiring = <ExpiringAssets<T>>::take(n);
+			for hash in expiring.iter() {
+				if let Some(asset) = <AssetsStore<T>>::get(hash) {
+					if !asset.deposit.is_zero() {
+						T::Currency::unreserve(&asset.owner, asset.deposit);
+						Self::deposit_event(Event::DepositReturned(
+							asset.owner.clone(),
+							asset.deposit,
+						));
+					}
+					<AssetsStore<T>>::remove(hash);
+					<MetadataStore<T>>::remove(hash);
+					let _ = <AdminsStore<T>>::clear_prefix(*hash, u32::MAX, None);
+					<AdminCount<T>>::remove(hash);
+					<ListingStore<T>>::remove(hash);
+					Self::deposit_event(Event::AssetExpired(*hash));
+				}
+			}
+			T::WeightInfo::on_initialize(expiring.len() as u32)
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -60,33 +191,30 @@ pub mod pallet {
 		/// An example dispatchable that takes a singles value as a parameter, writes the value to
 		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(T::WeightInfo::add_asset(asset_name.len() as u32))]
 		pub fn add_asset(
 			origin: OriginFor<T>,
 			asset_name: Vec<u8>,
 			meta: Option<Vec<u8>>,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
+			Self::do_add_asset(owner, asset_name, meta, None)
+		}
 
-			ensure!(asset_name.len() > 3, Error::<T>::ShortNameProvided);
-			ensure!(asset_name.len() < 32, Error::<T>::LongNameProvided);
-
-			let asset = AssetItem { name: asset_name.clone(), owner: owner.clone() };
-
-			let asset_hash = T::Hashing::hash_of(&asset);
-
-			// Update storage.
-			<AssetsStore<T>>::insert(asset_hash, asset);
-			<MetadataStore<T>>::insert(asset_hash, owner.clone(), meta.clone());
-
-			// Emit an event.
-			Self::deposit_event(Event::AssetWasStored(asset_name, owner.clone()));
-
-			// Return a successful DispatchResultWithPostInfo
-			Ok(())
+		/// Register an asset that is automatically reclaimed once block `expiry` is reached.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::add_asset_with_expiry(asset_name.len() as u32))]
+		pub fn add_asset_with_expiry(
+			origin: OriginFor<T>,
+			asset_name: Vec<u8>,
+			meta: Option<Vec<u8>>,
+			expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_add_asset(owner, asset_name, meta, expiry)
 		}
 		#[pallet::call_index(1)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(T::WeightInfo::transfer_asset())]
 		pub fn transfer_asset(
 			origin: OriginFor<T>,
 			hash: T::Hash,
@@ -98,34 +226,73 @@ pub mod pallet {
 
 			ensure!(asset.owner == owner, Error::<T>::InvalidOwner);
 
-			let new_asset = AssetItem { name: asset.name, owner: destination.clone() };
+			// The reserved deposit follows the asset to its new owner.
+			if !asset.deposit.is_zero() {
+				T::Currency::reserve(&destination, asset.deposit)?;
+				T::Currency::unreserve(&owner, asset.deposit);
+			}
+
+			let new_asset =
+				AssetItem { name: asset.name, owner: destination.clone(), deposit: asset.deposit };
 
 			<AssetsStore<T>>::insert(hash, new_asset);
+			<ListingStore<T>>::remove(hash);
+			// Admins appointed by the previous owner don't carry over to the new owner.
+			let _ = <AdminsStore<T>>::clear_prefix(hash, u32::MAX, None);
+			<AdminCount<T>>::remove(hash);
 
 			Ok(())
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(T::WeightInfo::update_meta())]
 		pub fn update_meta(
 			origin: OriginFor<T>,
 			hash: T::Hash,
 			meta: Option<Vec<u8>>,
 		) -> DispatchResult {
-			let owner = ensure_signed(origin)?;
+			let caller = ensure_signed(origin)?;
+
+			let mut asset = <AssetsStore<T>>::get(hash).ok_or(Error::<T>::InvalidHash)?;
 
+			// Either the asset owner or a registered admin may edit the metadata.
 			ensure!(
-				<MetadataStore<T>>::contains_key(hash, owner.clone()),
+				asset.owner == caller || <AdminsStore<T>>::contains_key(hash, caller.clone()),
 				Error::<T>::InvalidOwner
 			);
 
-			<MetadataStore<T>>::insert(hash, owner, meta.clone());
+			let meta = Self::bound_meta(meta)?;
+
+			// Metadata, and its deposit, always belong to the asset owner.
+			let owner = asset.owner.clone();
+
+			// Adjust the reserved metadata deposit for the difference between the deposit owed
+			// for the stored metadata and the deposit owed for the incoming metadata. Comparing
+			// presence (not just byte length) ensures the base fee is charged and refunded
+			// correctly when metadata is added to, or cleared from, an asset that had none.
+			let old_deposit = Self::metadata_deposit(<MetadataStore<T>>::get(hash).flatten());
+			let new_deposit = Self::metadata_deposit(meta.clone());
+
+			if new_deposit > old_deposit {
+				let extra = new_deposit.saturating_sub(old_deposit);
+				T::Currency::reserve(&owner, extra)?;
+				asset.deposit = asset.deposit.saturating_add(extra);
+				Self::deposit_event(Event::DepositReserved(owner.clone(), extra));
+			} else if old_deposit > new_deposit {
+				let refund = old_deposit.saturating_sub(new_deposit);
+				T::Currency::unreserve(&owner, refund);
+				asset.deposit = asset.deposit.saturating_sub(refund);
+				Self::deposit_event(Event::DepositReturned(owner.clone(), refund));
+			}
+
+			<AssetsStore<T>>::insert(hash, asset);
+			<MetadataStore<T>>::insert(hash, meta);
 
 			Ok(())
 		}
 
 		#[pallet::call_index(3)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(T::WeightInfo::register_admin())]
 		pub fn register_admin(
 			origin: OriginFor<T>,
 			hash: T::Hash,
@@ -137,9 +304,206 @@ pub mod pallet {
 
 			ensure!(asset.owner == owner, Error::<T>::InvalidOwner);
 
-			<MetadataStore<T>>::insert(hash, admin_address, None::<Vec<u8>>);
+			if !<AdminsStore<T>>::contains_key(hash, admin_address.clone()) {
+				let count = <AdminCount<T>>::get(hash);
+				ensure!(count < T::MaxAdminsPerAsset::get(), Error::<T>::TooManyAdmins);
+				<AdminsStore<T>>::insert(hash, admin_address.clone(), ());
+				<AdminCount<T>>::insert(hash, count + 1);
+			}
+
+			Self::deposit_event(Event::AdminRegistered(hash, admin_address));
 
 			Ok(())
 		}
+
+		/// Revoke an admin previously granted edit access to an asset. Owner-only.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::revoke_admin())]
+		pub fn revoke_admin(
+			origin: OriginFor<T>,
+			hash: T::Hash,
+			admin_address: T::AccountId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let asset = <AssetsStore<T>>::get(hash).ok_or(Error::<T>::InvalidHash)?;
+
+			ensure!(asset.owner == owner, Error::<T>::InvalidOwner);
+			ensure!(
+				<AdminsStore<T>>::contains_key(hash, admin_address.clone()),
+				Error::<T>::NotAnAdmin
+			);
+
+			<AdminsStore<T>>::remove(hash, admin_address.clone());
+			<AdminCount<T>>::mutate(hash, |count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::AdminRevoked(hash, admin_address));
+
+			Ok(())
+		}
+
+		/// Remove an asset, clear its stores and refund the owner's reserved deposit.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::remove_asset())]
+		pub fn remove_asset(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let asset = <AssetsStore<T>>::get(hash).ok_or(Error::<T>::InvalidHash)?;
+
+			ensure!(asset.owner == owner, Error::<T>::InvalidOwner);
+
+			if !asset.deposit.is_zero() {
+				T::Currency::unreserve(&owner, asset.deposit);
+				Self::deposit_event(Event::DepositReturned(owner.clone(), asset.deposit));
+			}
+
+			<AssetsStore<T>>::remove(hash);
+			<MetadataStore<T>>::remove(hash);
+			let _ = <AdminsStore<T>>::clear_prefix(hash, u32::MAX, None);
+			<AdminCount<T>>::remove(hash);
+			<ListingStore<T>>::remove(hash);
+
+			Ok(())
+		}
+
+		/// List an owned asset for sale at `price`. Owner-only.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::list_for_sale())]
+		pub fn list_for_sale(
+			origin: OriginFor<T>,
+			hash: T::Hash,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let asset = <AssetsStore<T>>::get(hash).ok_or(Error::<T>::InvalidHash)?;
+			ensure!(asset.owner == owner, Error::<T>::InvalidOwner);
+
+			<ListingStore<T>>::insert(hash, price);
+			Self::deposit_event(Event::AssetListed(hash, price));
+
+			Ok(())
+		}
+
+		/// Remove an asset's active listing. Owner-only.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::unlist())]
+		pub fn unlist(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let asset = <AssetsStore<T>>::get(hash).ok_or(Error::<T>::InvalidHash)?;
+			ensure!(asset.owner == owner, Error::<T>::InvalidOwner);
+			ensure!(<ListingStore<T>>::contains_key(hash), Error::<T>::NotListed);
+
+			<ListingStore<T>>::remove(hash);
+			Self::deposit_event(Event::AssetUnlisted(hash));
+
+			Ok(())
+		}
+
+		/// Buy a listed asset, paying the listed price to its current owner.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::buy_asset())]
+		pub fn buy_asset(origin: OriginFor<T>, hash: T::Hash) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+
+			let mut asset = <AssetsStore<T>>::get(hash).ok_or(Error::<T>::InvalidHash)?;
+			let price = <ListingStore<T>>::get(hash).ok_or(Error::<T>::NotListed)?;
+			let seller = asset.owner.clone();
+
+			T::Currency::transfer(&buyer, &seller, price, ExistenceRequirement::KeepAlive)?;
+
+			// The reserved deposit follows the asset to its new owner.
+			if !asset.deposit.is_zero() {
+				T::Currency::reserve(&buyer, asset.deposit)?;
+				T::Currency::unreserve(&seller, asset.deposit);
+			}
+
+			asset.owner = buyer.clone();
+			<AssetsStore<T>>::insert(hash, asset);
+			<ListingStore<T>>::remove(hash);
+			// Admins appointed by the previous owner don't carry over to the new owner.
+			let _ = <AdminsStore<T>>::clear_prefix(hash, u32::MAX, None);
+			<AdminCount<T>>::remove(hash);
+
+			Self::deposit_event(Event::AssetSold(hash, seller, buyer, price));
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Reserve the deposit, store the asset and optionally schedule it to expire.
+		fn do_add_asset(
+			owner: T::AccountId,
+			asset_name: Vec<u8>,
+			meta: Option<Vec<u8>>,
+			expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let name = Self::bound_name(asset_name)?;
+			let meta = Self::bound_meta(meta)?;
+
+			// The base asset deposit, plus a metadata deposit when metadata is supplied.
+			let deposit = T::AssetDeposit::get().saturating_add(Self::metadata_deposit(meta.clone()));
+
+			let asset = AssetItem { name: name.clone(), owner: owner.clone(), deposit };
+			let asset_hash = T::Hashing::hash_of(&asset);
+			ensure!(!<AssetsStore<T>>::contains_key(asset_hash), Error::<T>::AssetAlreadyExists);
+
+			T::Currency::reserve(&owner, deposit)?;
+
+			if let Some(expiry) = expiry {
+				ensure!(
+					expiry > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::ExpiryInPast
+				);
+				<ExpiringAssets<T>>::try_mutate(expiry, |scheduled| {
+					scheduled.try_push(asset_hash).map_err(|_| Error::<T>::TooManyExpiringAssets)
+				})?;
+			}
+
+			// Update storage.
+			<AssetsStore<T>>::insert(asset_hash, &asset);
+			<MetadataStore<T>>::insert(asset_hash, meta);
+
+			// Emit an event.
+			Self::deposit_event(Event::DepositReserved(owner.clone(), deposit));
+			Self::deposit_event(Event::AssetWasStored(name.to_vec(), owner));
+
+			Ok(())
+		}
+
+		/// The deposit owed for a metadata value: zero when absent, or the base fee plus a
+		/// per-byte fee when present. Charging the base fee only on presence (not byte length)
+		/// is what lets `update_meta` tell "no metadata" and "empty metadata" apart.
+		fn metadata_deposit(meta: Option<BoundedName<T>>) -> BalanceOf<T> {
+			match meta {
+				Some(meta) => T::MetadataDepositBase::get().saturating_add(
+					T::MetadataDepositPerByte::get().saturating_mul((meta.len() as u32).into()),
+				),
+				None => Zero::zero(),
+			}
+		}
+
+		/// Validate an asset name against the configured length bounds.
+		fn bound_name(name: Vec<u8>) -> Result<BoundedName<T>, Error<T>> {
+			ensure!(
+				name.len() >= T::NameMinLength::get() as usize,
+				Error::<T>::ShortNameProvided
+			);
+			name.try_into().map_err(|_| Error::<T>::LongNameProvided)
+		}
+
+		/// Validate an optional metadata value against the configured length bound.
+		fn bound_meta(meta: Option<Vec<u8>>) -> Result<Option<BoundedName<T>>, Error<T>> {
+			match meta {
+				Some(meta) => {
+					let meta: BoundedName<T> =
+						meta.try_into().map_err(|_| Error::<T>::LongNameProvided)?;
+					Ok(Some(meta))
+				},
+				None => Ok(None),
+			}
+		}
 	}
-}
\ No newline at end of file
+}
@@ -0,0 +1,204 @@
+use crate::{mock::*, AdminsStore, AssetsStore, Error, Event};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, Hooks, ReservableCurrency},
+};
+
+fn asset_hash() -> sp_core::H256 {
+	AssetsStore::<Test>::iter().next().expect("an asset was stored").0
+}
+
+fn last_event() -> Event<Test> {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::MetaAssets(inner) => Some(inner),
+			_ => None,
+		})
+		.expect("a MetaAssets event was deposited")
+}
+
+#[test]
+fn add_asset_without_meta_reserves_only_the_asset_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MetaAssets::add_asset(RuntimeOrigin::signed(1), b"token".to_vec(), None));
+		assert_eq!(Balances::reserved_balance(1), 10);
+	});
+}
+
+#[test]
+fn update_meta_from_none_charges_the_full_metadata_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MetaAssets::add_asset(RuntimeOrigin::signed(1), b"token".to_vec(), None));
+		assert_eq!(Balances::reserved_balance(1), 10);
+
+		// Adding 5 bytes of metadata to an asset that had none must charge the base fee (5)
+		// plus the per-byte fee (5), not just the per-byte fee.
+		assert_ok!(MetaAssets::update_meta(
+			RuntimeOrigin::signed(1),
+			asset_hash(),
+			Some(vec![b'm'; 5])
+		));
+		assert_eq!(Balances::reserved_balance(1), 20);
+	});
+}
+
+#[test]
+fn update_meta_back_to_none_refunds_the_full_metadata_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MetaAssets::add_asset(
+			RuntimeOrigin::signed(1),
+			b"token".to_vec(),
+			Some(vec![b'm'; 5])
+		));
+		assert_eq!(Balances::reserved_balance(1), 20);
+
+		assert_ok!(MetaAssets::update_meta(RuntimeOrigin::signed(1), asset_hash(), None));
+		assert_eq!(Balances::reserved_balance(1), 10);
+	});
+}
+
+#[test]
+fn remove_asset_refunds_the_full_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MetaAssets::add_asset(
+			RuntimeOrigin::signed(1),
+			b"token".to_vec(),
+			Some(vec![b'm'; 5])
+		));
+		let hash = asset_hash();
+		assert_ok!(MetaAssets::remove_asset(RuntimeOrigin::signed(1), hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(AssetsStore::<Test>::get(hash).is_none());
+	});
+}
+
+#[test]
+fn add_asset_rejects_a_duplicate_registration() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MetaAssets::add_asset(RuntimeOrigin::signed(1), b"token".to_vec(), None));
+		assert_noop!(
+			MetaAssets::add_asset(RuntimeOrigin::signed(1), b"token".to_vec(), None),
+			Error::<Test>::AssetAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn registered_admin_may_update_meta_but_a_stranger_may_not() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MetaAssets::add_asset(RuntimeOrigin::signed(1), b"token".to_vec(), None));
+		let hash = asset_hash();
+
+		assert_noop!(
+			MetaAssets::update_meta(RuntimeOrigin::signed(2), hash, Some(vec![b'm'; 4])),
+			Error::<Test>::InvalidOwner
+		);
+
+		assert_ok!(MetaAssets::register_admin(RuntimeOrigin::signed(1), hash, 2));
+		assert_ok!(MetaAssets::update_meta(RuntimeOrigin::signed(2), hash, Some(vec![b'm'; 4])));
+
+		assert_ok!(MetaAssets::revoke_admin(RuntimeOrigin::signed(1), hash, 2));
+		assert_noop!(
+			MetaAssets::update_meta(RuntimeOrigin::signed(2), hash, None),
+			Error::<Test>::InvalidOwner
+		);
+	});
+}
+
+#[test]
+fn transfer_asset_moves_the_deposit_and_drops_the_old_owners_admins() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MetaAssets::add_asset(RuntimeOrigin::signed(1), b"token".to_vec(), None));
+		let hash = asset_hash();
+		assert_ok!(MetaAssets::register_admin(RuntimeOrigin::signed(1), hash, 3));
+
+		assert_ok!(MetaAssets::transfer_asset(RuntimeOrigin::signed(1), hash, 2));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert_eq!(MetaAssets::assets(hash).unwrap().owner, 2);
+		assert!(!AdminsStore::<Test>::contains_key(hash, 3));
+
+		// The old owner has lost control of the asset.
+		assert_noop!(
+			MetaAssets::transfer_asset(RuntimeOrigin::signed(1), hash, 1),
+			Error::<Test>::InvalidOwner
+		);
+	});
+}
+
+#[test]
+fn listing_lifecycle_moves_ownership_and_clears_the_listing() {
+	new_test_ext().execute_with(|| {
+		// Events are not recorded at genesis (block 0).
+		System::set_block_number(1);
+		assert_ok!(MetaAssets::add_asset(RuntimeOrigin::signed(1), b"token".to_vec(), None));
+		let hash = asset_hash();
+		assert_ok!(MetaAssets::register_admin(RuntimeOrigin::signed(1), hash, 3));
+
+		assert_ok!(MetaAssets::list_for_sale(RuntimeOrigin::signed(1), hash, 50));
+		assert_eq!(MetaAssets::listing(hash), Some(50));
+
+		assert_ok!(MetaAssets::buy_asset(RuntimeOrigin::signed(2), hash));
+
+		assert_eq!(MetaAssets::assets(hash).unwrap().owner, 2);
+		assert_eq!(MetaAssets::listing(hash), None);
+		assert_eq!(Balances::free_balance(1), 1_050);
+		assert_eq!(Balances::free_balance(2), 940);
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert!(!AdminsStore::<Test>::contains_key(hash, 3));
+		assert_eq!(last_event(), Event::AssetSold(hash, 1, 2, 50));
+	});
+}
+
+#[test]
+fn buy_asset_fails_when_the_asset_is_not_listed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MetaAssets::add_asset(RuntimeOrigin::signed(1), b"token".to_vec(), None));
+		let hash = asset_hash();
+
+		assert_noop!(
+			MetaAssets::buy_asset(RuntimeOrigin::signed(2), hash),
+			Error::<Test>::NotListed
+		);
+	});
+}
+
+#[test]
+fn expiring_assets_are_reclaimed_and_their_listing_is_cleared() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(MetaAssets::add_asset_with_expiry(
+			RuntimeOrigin::signed(1),
+			b"token".to_vec(),
+			None,
+			Some(2),
+		));
+		let hash = asset_hash();
+		assert_ok!(MetaAssets::list_for_sale(RuntimeOrigin::signed(1), hash, 50));
+
+		MetaAssets::on_initialize(2);
+
+		assert!(AssetsStore::<Test>::get(hash).is_none());
+		assert_eq!(MetaAssets::listing(hash), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn add_asset_with_expiry_rejects_a_non_future_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_noop!(
+			MetaAssets::add_asset_with_expiry(
+				RuntimeOrigin::signed(1),
+				b"token".to_vec(),
+				None,
+				Some(5),
+			),
+			Error::<Test>::ExpiryInPast
+		);
+	});
+}
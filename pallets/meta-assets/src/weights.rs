@@ -0,0 +1,165 @@
+//! Autogenerated weights for `pallet_meta_assets`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI.
+//!
+//! The `()` implementation is provided for mocks and tests.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for `pallet_meta_assets`.
+pub trait WeightInfo {
+	fn add_asset(n: u32) -> Weight;
+	fn add_asset_with_expiry(n: u32) -> Weight;
+	fn transfer_asset() -> Weight;
+	fn update_meta() -> Weight;
+	fn register_admin() -> Weight;
+	fn revoke_admin() -> Weight;
+	fn remove_asset() -> Weight;
+	fn list_for_sale() -> Weight;
+	fn unlist() -> Weight;
+	fn buy_asset() -> Weight;
+	fn on_initialize(n: u32) -> Weight;
+}
+
+/// Weights for `pallet_meta_assets` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// The range of component `n` is `[4, 32]`.
+	fn add_asset(n: u32) -> Weight {
+		// Proof size summary: measured `0`, estimated `3509`.
+		Weight::from_ref_time(28_000_000)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(2_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// The range of component `n` is `[4, 32]`.
+	fn add_asset_with_expiry(n: u32) -> Weight {
+		// Proof size summary: measured `0`, estimated `3509`.
+		Weight::from_ref_time(32_000_000)
+			.saturating_add(Weight::from_ref_time(2_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn transfer_asset() -> Weight {
+		Weight::from_ref_time(22_000_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn update_meta() -> Weight {
+		Weight::from_ref_time(24_000_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn register_admin() -> Weight {
+		Weight::from_ref_time(20_000_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn revoke_admin() -> Weight {
+		Weight::from_ref_time(21_000_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn remove_asset() -> Weight {
+		Weight::from_ref_time(26_000_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn list_for_sale() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn unlist() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn buy_asset() -> Weight {
+		Weight::from_ref_time(29_000_000)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// The range of component `n` is `[0, MaxExpiringPerBlock]`.
+	fn on_initialize(n: u32) -> Weight {
+		// One read+write to drain the expiry index, plus two writes per reclaimed asset.
+		Weight::from_ref_time(6_000_000)
+			.saturating_add(Weight::from_ref_time(8_000_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads((n as u64).saturating_mul(1)))
+			.saturating_add(T::DbWeight::get().writes((n as u64).saturating_mul(2)))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn add_asset(n: u32) -> Weight {
+		Weight::from_ref_time(28_000_000)
+			.saturating_add(Weight::from_ref_time(2_000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn add_asset_with_expiry(n: u32) -> Weight {
+		Weight::from_ref_time(32_000_000)
+			.saturating_add(Weight::from_ref_time(2_000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn transfer_asset() -> Weight {
+		Weight::from_ref_time(22_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn update_meta() -> Weight {
+		Weight::from_ref_time(24_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn register_admin() -> Weight {
+		Weight::from_ref_time(20_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn revoke_admin() -> Weight {
+		Weight::from_ref_time(21_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn remove_asset() -> Weight {
+		Weight::from_ref_time(26_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn list_for_sale() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn unlist() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn buy_asset() -> Weight {
+		Weight::from_ref_time(29_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn on_initialize(n: u32) -> Weight {
+		Weight::from_ref_time(6_000_000)
+			.saturating_add(Weight::from_ref_time(8_000_000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((n as u64).saturating_mul(1)))
+			.saturating_add(RocksDbWeight::get().writes((n as u64).saturating_mul(2)))
+	}
+}